@@ -13,6 +13,7 @@ use release_plz::{Command, Release};
 use toolchain::Toolchain;
 
 #[derive(Debug, Clone, Setters)]
+#[setters(strip_option)]
 pub struct Workflow {
     /// When enabled, a release job is added to the workflow.
     /// *IMPORTANT:* Ensure `secrets.CARGO_REGISTRY_TOKEN` is set for your
@@ -25,8 +26,57 @@ pub struct Workflow {
     /// When enabled, a benchmark job is added to the workflow.
     pub benchmarks: bool,
 
+    /// Controls when the benchmarks added by [`Workflow::benchmarks`]
+    /// actually run. Defaults to running on every push and pull request.
+    pub benchmark_policy: BenchmarkPolicy,
+
     /// When enabled, auto-commits lint and fmt fixes on PRs.
     pub auto_fix: bool,
+
+    /// When set, expands `build_and_test` into a `strategy.matrix` over the
+    /// given operating systems and toolchains instead of running once on the
+    /// default runner.
+    pub matrix: Option<BuildMatrix>,
+
+    /// When enabled, caches `~/.cargo/registry`, `~/.cargo/git` and
+    /// `./target` across runs, keyed on `Cargo.lock`.
+    pub cache: bool,
+
+    /// When enabled, installs `mold` and uses it to link the crate, which is
+    /// usually much faster than the platform default linker.
+    pub fast_linker: bool,
+
+    /// When enabled, adds a job that verifies the crate builds on its
+    /// minimum supported Rust version. [`MsrvCheck::Detect`] reads the
+    /// version out of the repo's `rust-toolchain`/`rust-toolchain.toml` file
+    /// at runtime; [`MsrvCheck::Pinned`] pins an explicit version instead.
+    pub msrv: Option<MsrvCheck>,
+
+    /// When set, adds a job that collects code coverage via `cargo-llvm-cov`
+    /// and uploads it to Codecov. *IMPORTANT:* Ensure `secrets.CODECOV_TOKEN`
+    /// is set for your github action.
+    pub coverage: Option<Coverage>,
+
+    /// When set, adds a job that publishes `cargo doc` output to GitHub
+    /// Pages on every push to `main`.
+    pub publish_docs: Option<PublishDocs>,
+
+    /// When set, adds a `schedule`-triggered job that runs `cargo update`
+    /// and opens (or refreshes) a pull request with the result.
+    pub dependency_updates: Option<DependencyUpdates>,
+
+    /// When enabled, adds a `merge_group` trigger so CI gates merge-queue
+    /// entries.
+    pub merge_queue: bool,
+
+    /// When set, adds a `workflow_dispatch` trigger with the given inputs,
+    /// e.g. a release `tag`. The inputs are only declared on the trigger; the
+    /// release jobs do not read them and run only on pushes to `main`.
+    pub manual_dispatch: Option<ManualDispatch>,
+
+    /// Glob patterns (e.g. `**.md`) for which changes alone should skip CI.
+    /// Attached as `paths-ignore` on the push and pull_request triggers.
+    pub paths_ignore: Vec<String>,
 }
 
 impl Default for Workflow {
@@ -35,9 +85,229 @@ impl Default for Workflow {
             auto_release: false,
             name: "CI".into(),
             benchmarks: false,
+            benchmark_policy: BenchmarkPolicy::default(),
             auto_fix: false,
+            matrix: None,
+            cache: false,
+            fast_linker: false,
+            msrv: None,
+            coverage: None,
+            publish_docs: None,
+            dependency_updates: None,
+            merge_queue: false,
+            manual_dispatch: None,
+            paths_ignore: Vec::new(),
+        }
+    }
+}
+
+/// Configuration for the `workflow_dispatch` trigger appended when
+/// [`Workflow::manual_dispatch`] is set.
+#[derive(Debug, Clone, Setters)]
+pub struct ManualDispatch {
+    /// Declared inputs, e.g. a release `tag`.
+    pub inputs: Vec<DispatchInput>,
+}
+
+impl ManualDispatch {
+    pub fn new() -> Self {
+        Self { inputs: Vec::new() }
+    }
+
+    pub fn add_input(mut self, input: DispatchInput) -> Self {
+        self.inputs.push(input);
+        self
+    }
+}
+
+impl Default for ManualDispatch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A single `workflow_dispatch` input declaration.
+#[derive(Debug, Clone, Setters)]
+#[setters(strip_option)]
+pub struct DispatchInput {
+    /// Name of the input, e.g. `tag`.
+    pub name: String,
+
+    /// Human-readable description shown in the "Run workflow" form.
+    pub description: Option<String>,
+
+    /// Whether the input must be supplied when dispatching manually.
+    pub required: bool,
+
+    /// Default value used when the input is omitted.
+    pub default: Option<String>,
+
+    /// GitHub input type, e.g. `boolean`, `choice`, `environment`. Defaults
+    /// to `string` when unset.
+    pub input_type: Option<String>,
+}
+
+impl DispatchInput {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+            required: false,
+            default: None,
+            input_type: None,
+        }
+    }
+}
+
+/// Controls when the benchmarks enabled by [`Workflow::benchmarks`] actually
+/// run. [`BenchmarkPolicy::Always`] keeps the bench step inline in the
+/// "Build and Test" job; the other variants run benchmarks in their own
+/// "Benchmarks" job instead, which holds no test or lint steps. The "Build
+/// and Test" job itself is not gated and also runs on those triggers.
+#[derive(Debug, Clone, Default)]
+pub enum BenchmarkPolicy {
+    /// Run inline, in the "Build and Test" job, on every push and pull
+    /// request.
+    #[default]
+    Always,
+
+    /// Run in a standalone "Benchmarks" job, only when manually dispatched
+    /// with the `should_bench` input set to `true`. Declares that input on
+    /// the `workflow_dispatch` trigger.
+    ManualOnly,
+
+    /// Run in a standalone "Benchmarks" job, only on a nightly `schedule`
+    /// cron.
+    Nightly(String),
+}
+
+/// Name of the boolean `workflow_dispatch` input gating benchmarks when
+/// [`BenchmarkPolicy::ManualOnly`] is selected.
+const SHOULD_BENCH_INPUT: &str = "should_bench";
+
+/// Configuration for the scheduled dependency-update job appended when
+/// [`Workflow::dependency_updates`] is set.
+#[derive(Debug, Clone, Setters)]
+pub struct DependencyUpdates {
+    /// Cron schedule controlling how often `cargo update` runs, e.g. weekly
+    /// vs. daily.
+    pub cron: String,
+
+    /// Branch the update PR is opened from.
+    pub branch: String,
+}
+
+impl Default for DependencyUpdates {
+    fn default() -> Self {
+        Self {
+            cron: "0 0 * * 0".into(), // Weekly, midnight on Sunday.
+            branch: "cargo_update".into(),
+        }
+    }
+}
+
+/// Configuration for the documentation-publishing job appended when
+/// [`Workflow::publish_docs`] is set.
+#[derive(Debug, Clone, Setters)]
+pub struct PublishDocs {
+    /// Branch that the generated `target/doc` is force-pushed to.
+    pub branch: String,
+}
+
+impl Default for PublishDocs {
+    fn default() -> Self {
+        Self {
+            branch: "gh-pages".into(),
+        }
+    }
+}
+
+/// Configuration for the coverage job appended when [`Workflow::coverage`]
+/// is set.
+#[derive(Debug, Clone, Setters)]
+#[setters(strip_option)]
+pub struct Coverage {
+    /// Minimum coverage percentage the Codecov status check requires.
+    pub target: Option<f32>,
+
+    /// Maximum allowed coverage drop (in percentage points) versus the base
+    /// branch before the Codecov status check fails.
+    pub fail_on_decrease: Option<f32>,
+}
+
+impl Coverage {
+    pub fn new() -> Self {
+        Self {
+            target: None,
+            fail_on_decrease: None,
+        }
+    }
+}
+
+impl Default for Coverage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// How the minimum supported Rust version is determined for the MSRV job.
+#[derive(Debug, Clone)]
+pub enum MsrvCheck {
+    /// Read the MSRV from the repo's `rust-toolchain`/`rust-toolchain.toml`
+    /// file at runtime.
+    Detect,
+
+    /// Pin the MSRV to an explicit version, skipping detection.
+    Pinned(String),
+}
+
+/// Describes the OS/toolchain grid that `build_and_test` is expanded over
+/// when [`Workflow::matrix`] is set.
+#[derive(Debug, Clone, Setters)]
+#[setters(strip_option)]
+pub struct BuildMatrix {
+    /// Runners the build should execute on, e.g. `ubuntu-latest`,
+    /// `macos-latest`, `windows-latest`.
+    pub os: Vec<String>,
+
+    /// Toolchain channels to test against, e.g. `stable`, `beta`, `nightly`,
+    /// or a pinned version such as `1.78.0`.
+    pub toolchain: Vec<String>,
+
+    /// Cancel all remaining matrix jobs as soon as one cell fails. Mirrors
+    /// GitHub's `strategy.fail-fast` (defaults to `true` when unset).
+    pub fail_fast: Option<bool>,
+
+    /// Toolchain channels that are allowed to fail without failing the
+    /// overall job, e.g. `nightly`. Emitted as a per-cell `continue-on-error`.
+    pub experimental: Vec<String>,
+}
+
+impl BuildMatrix {
+    pub fn new(os: Vec<String>, toolchain: Vec<String>) -> Self {
+        Self {
+            os,
+            toolchain,
+            fail_fast: None,
+            experimental: Vec::new(),
         }
     }
+
+    /// Converts this matrix into the `strategy` block consumed by
+    /// [`Job::strategy`].
+    fn to_strategy(&self) -> Strategy {
+        let mut strategy = Strategy::default().matrix(
+            Matrix::default()
+                .add("os", self.os.clone())
+                .add("toolchain", self.toolchain.clone()),
+        );
+
+        if let Some(fail_fast) = self.fail_fast {
+            strategy = strategy.fail_fast(fail_fast);
+        }
+
+        strategy
+    }
 }
 
 impl Workflow {
@@ -54,16 +324,38 @@ impl Workflow {
 
     /// Creates the "Build and Test" job for the workflow.
     pub fn build_and_test(&self) -> Job {
+        // The fmt/clippy steps below always run against nightly, so nightly
+        // (with its components) must be installed alongside the matrix
+        // toolchain even when the matrix cell itself is stable/beta/pinned.
+        let toolchain = if self.matrix.is_some() {
+            Toolchain::default()
+                .add_version("${{ matrix.toolchain }}")
+                .add_nightly()
+                .add_clippy()
+                .add_fmt()
+        } else {
+            Toolchain::default()
+                .add_stable()
+                .add_nightly()
+                .add_clippy()
+                .add_fmt()
+        };
+
         let mut job = Job::new("Build and Test")
             .permissions(Permissions::default().contents(Level::Read))
-            .add_step(Step::checkout())
-            .add_step(
-                Toolchain::default()
-                    .add_stable()
-                    .add_nightly()
-                    .add_clippy()
-                    .add_fmt(),
-            )
+            .add_step(Step::checkout());
+
+        if self.fast_linker {
+            job = job.add_step(setup_mold_step());
+        }
+
+        if self.cache {
+            let extra_key = self.matrix.is_some().then_some("${{ matrix.toolchain }}");
+            job = job.add_step(cache_step(extra_key));
+        }
+
+        job = job
+            .add_step(toolchain)
             .add_step(
                 Cargo::new("test")
                     .args("--all-features --workspace")
@@ -82,10 +374,32 @@ impl Workflow {
                     .name("Cargo Clippy"),
             );
 
-        if self.benchmarks {
+        // Non-`Always` policies run benchmarks in their own job (see
+        // `benchmarks_job`), gated on the schedule or dispatch input, so
+        // ordinary pushes and pull requests skip them.
+        if self.benchmarks && matches!(self.benchmark_policy, BenchmarkPolicy::Always) {
             job = job.add_step(Cargo::new("bench").args("--workspace").name("Cargo Bench"));
         }
 
+        if let Some(matrix) = &self.matrix {
+            job = job
+                .runs_on(Expression::new("${{ matrix.os }}"))
+                .strategy(matrix.to_strategy());
+
+            if !matrix.experimental.is_empty() {
+                let experimental = matrix
+                    .experimental
+                    .iter()
+                    .map(|toolchain| format!("\"{toolchain}\""))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                job = job.continue_on_error(Expression::new(format!(
+                    "${{{{ contains(fromJSON('[{experimental}]'), matrix.toolchain) }}}}"
+                )));
+            }
+        }
+
         job
     }
 }
@@ -94,34 +408,81 @@ impl From<Workflow> for GHWorkflow {
     fn from(value: Workflow) -> Self {
         let flags = RustFlags::deny("warnings");
 
-        let event = Event::default()
-            .push(Push::default().add_branch("main"))
-            .pull_request(
-                PullRequest::default()
-                    .add_type(PullRequestType::Opened)
-                    .add_type(PullRequestType::Synchronize)
-                    .add_type(PullRequestType::Reopened)
-                    .add_branch("main"),
-            );
+        let mut push = Push::default().add_branch("main");
+        let mut pull_request = PullRequest::default()
+            .add_type(PullRequestType::Opened)
+            .add_type(PullRequestType::Synchronize)
+            .add_type(PullRequestType::Reopened)
+            .add_branch("main");
+
+        for glob in &value.paths_ignore {
+            push = push.add_path_ignore(glob.clone());
+            pull_request = pull_request.add_path_ignore(glob.clone());
+        }
+
+        let mut event = Event::default().push(push).pull_request(pull_request);
+
+        if value.merge_queue {
+            event = event.merge_group(MergeGroup::default());
+        }
+
+        let mut manual_dispatch = value.manual_dispatch.clone();
+        if value.benchmarks && matches!(value.benchmark_policy, BenchmarkPolicy::ManualOnly) {
+            let should_bench = DispatchInput::new(SHOULD_BENCH_INPUT)
+                .description("Run the benchmark suite for this run")
+                .default("false")
+                .input_type("boolean");
+            manual_dispatch = Some(manual_dispatch.unwrap_or_default().add_input(should_bench));
+        }
+
+        if let Some(manual_dispatch) = &manual_dispatch {
+            event = event.workflow_dispatch(to_workflow_dispatch(manual_dispatch));
+        }
+
+        let mut schedules = Vec::new();
+        if let Some(dependency_updates) = &value.dependency_updates {
+            schedules.push(Schedule::new(dependency_updates.cron.clone()));
+        }
+        if let BenchmarkPolicy::Nightly(cron) = &value.benchmark_policy {
+            if value.benchmarks {
+                schedules.push(Schedule::new(cron.clone()));
+            }
+        }
+        if !schedules.is_empty() {
+            event = event.schedule(schedules);
+        }
 
         let is_main = Context::github().ref_().eq("refs/heads/main".into());
         let is_push = Context::github().event_name().eq("push".into());
         let cond = is_main.and(is_push);
+        let docs_cond = cond.clone();
 
         // Jobs
         let build = value.build_and_test();
         let mut workflow = GHWorkflow::new(value.name)
             .add_env(flags)
             .on(event)
+            .concurrency(
+                Concurrency::new(Expression::new(
+                    "${{ github.head_ref || github.ref || github.run_id }}",
+                ))
+                .cancel_in_progress(true),
+            )
             .add_job("build", build.clone());
 
+        if value.benchmarks && !matches!(value.benchmark_policy, BenchmarkPolicy::Always) {
+            let benchmarks =
+                benchmarks_job(&value.benchmark_policy, value.cache, value.fast_linker);
+            workflow = workflow.add_job("benchmarks", benchmarks);
+        }
+
         if value.auto_release {
             let permissions = Permissions::default()
                 .pull_requests(Level::Write)
                 .packages(Level::Write)
                 .contents(Level::Write);
 
-            let release = release_job(&cond, &build, &permissions);
+            let release = release_job(&cond, &build, &permissions, value.cache, value.fast_linker);
             let release_pr = release_pr_job(cond, &build, permissions);
             workflow = workflow
                 .add_job("release", release)
@@ -131,20 +492,306 @@ impl From<Workflow> for GHWorkflow {
         // Add auto-fix job if enabled
         if value.auto_fix {
             let is_pr = Context::github().event_name().eq("pull_request".into());
-            let lint_and_fmt_fix = lint_and_fmt_fix_job();
+            let lint_and_fmt_fix = lint_and_fmt_fix_job(value.cache, value.fast_linker);
             workflow = workflow.add_job("auto-fix-lint-fmt", lint_and_fmt_fix.cond(is_pr));
         }
 
+        if let Some(msrv) = &value.msrv {
+            for (id, job) in msrv_jobs(msrv) {
+                workflow = workflow.add_job(id, job);
+            }
+        }
+
+        if let Some(coverage) = &value.coverage {
+            workflow = workflow.add_job("coverage", coverage_job(coverage));
+        }
+
+        if let Some(publish_docs) = &value.publish_docs {
+            let docs = publish_docs_job(docs_cond, publish_docs, value.cache, value.fast_linker);
+            workflow = workflow.add_job("publish-docs", docs);
+        }
+
+        if let Some(dependency_updates) = &value.dependency_updates {
+            let update = dependency_updates_job(dependency_updates);
+            workflow = workflow.add_job("update-dependencies", update);
+        }
+
         workflow
     }
 }
 
-fn lint_and_fmt_fix_job() -> Job {
-    Job::new("Auto Fix Lint and Fmt")
+/// Builds the standalone "Benchmarks" job used when `benchmark_policy` is
+/// anything other than [`BenchmarkPolicy::Always`]. The whole job is gated,
+/// and it holds only checkout, toolchain and `cargo bench` steps; the
+/// "Build and Test" job is not gated and runs on the same trigger.
+fn benchmarks_job(policy: &BenchmarkPolicy, cache: bool, fast_linker: bool) -> Job {
+    let cond = match policy {
+        BenchmarkPolicy::Always => unreachable!("Always policy keeps benchmarks inline"),
+        BenchmarkPolicy::ManualOnly => Expression::new(format!(
+            "${{{{ github.event_name == 'workflow_dispatch' && github.event.inputs.{SHOULD_BENCH_INPUT} == 'true' }}}}"
+        )),
+        BenchmarkPolicy::Nightly(cron) => Expression::new(format!(
+            "${{{{ github.event_name == 'schedule' && github.event.schedule == '{cron}' }}}}"
+        )),
+    };
+
+    let mut job = Job::new("Benchmarks")
+        .cond(cond)
+        .permissions(Permissions::default().contents(Level::Read))
+        .add_step(Step::checkout());
+
+    if fast_linker {
+        job = job.add_step(setup_mold_step());
+    }
+
+    if cache {
+        job = job.add_step(cache_step(None));
+    }
+
+    job.add_step(Toolchain::default().add_stable())
+        .add_step(Cargo::new("bench").args("--workspace").name("Cargo Bench"))
+}
+
+/// Converts [`ManualDispatch`] into the `workflow_dispatch` trigger consumed
+/// by [`Event::workflow_dispatch`].
+fn to_workflow_dispatch(manual_dispatch: &ManualDispatch) -> WorkflowDispatch {
+    let mut dispatch = WorkflowDispatch::default();
+
+    for input in &manual_dispatch.inputs {
+        let mut gh_input = Input::default().required(input.required);
+
+        if let Some(description) = &input.description {
+            gh_input = gh_input.description(description.clone());
+        }
+
+        if let Some(default) = &input.default {
+            gh_input = gh_input.default(default.clone());
+        }
+
+        if let Some(input_type) = &input.input_type {
+            gh_input = gh_input.input_type(input_type.clone());
+        }
+
+        dispatch = dispatch.add_input(input.name.clone(), gh_input);
+    }
+
+    dispatch
+}
+
+/// Builds the job that runs `cargo update` on a schedule and opens (or
+/// refreshes) a pull request carrying the resulting `Cargo.lock` changes.
+/// No-ops gracefully when `cargo update` produces no diff.
+fn dependency_updates_job(dependency_updates: &DependencyUpdates) -> Job {
+    let branch = &dependency_updates.branch;
+    let cron = &dependency_updates.cron;
+
+    Job::new("Update Dependencies")
+        .cond(Expression::new(format!(
+            "${{{{ github.event_name == 'schedule' && github.event.schedule == '{cron}' }}}}"
+        )))
+        .permissions(
+            Permissions::default()
+                .contents(Level::Write)
+                .pull_requests(Level::Write),
+        )
+        .add_env(Env::github())
+        .add_step(Step::checkout())
+        .add_step(Toolchain::default().add_stable())
+        .add_step(Cargo::new("update").name("Cargo Update"))
+        .add_step(Step::run(format!(
+            r#"
+            if git diff --quiet -- Cargo.lock; then
+              echo "No dependency updates available."
+              exit 0
+            fi
+
+            SUMMARY=$(git diff -- Cargo.lock | grep -E '^[+-]name|^[+-]version' | sed 's/^+/Updated: /;s/^-//')
+
+            git config user.name "github-actions[bot]"
+            git config user.email "github-actions[bot]@users.noreply.github.com"
+            git checkout -B {branch}
+            git add Cargo.lock
+            git commit -m "chore: cargo update" -m "$SUMMARY"
+            git push --force origin {branch}
+
+            if gh pr view {branch} --json number >/dev/null 2>&1; then
+              gh pr edit {branch} --body "$SUMMARY"
+            else
+              gh pr create --head {branch} --title "chore: cargo update" --body "$SUMMARY"
+            fi
+        "#,
+            branch = branch
+        ))
+        .name("Open Dependency Update PR"))
+}
+
+/// Builds the job that publishes `cargo doc` output to GitHub Pages. The
+/// job force-pushes a single commit so git GC reclaims prior builds, and
+/// generates a redirect `index.html` so the Pages root lands on the crate's
+/// docs.
+fn publish_docs_job(
+    cond: Context<bool>,
+    docs: &PublishDocs,
+    cache: bool,
+    fast_linker: bool,
+) -> Job {
+    let mut job = Job::new("Publish Docs")
+        .cond(cond)
         .permissions(Permissions::default().contents(Level::Write))
-        .cond(Context::github().event_name().eq("pull_request".into())) // Ensure it's a PR
+        .add_step(Step::checkout());
+
+    if fast_linker {
+        job = job.add_step(setup_mold_step());
+    }
+
+    if cache {
+        job = job.add_step(cache_step(None));
+    }
+
+    job.add_step(Toolchain::default().add_stable())
+        .add_step(
+            Cargo::new("doc")
+                .args("--no-deps --all-features")
+                .name("Cargo Doc"),
+        )
+        .add_step(
+            Step::run(
+                r#"CRATE_NAME=$(sed -n 's/^name = "\(.*\)"/\1/p' Cargo.toml | head -n1 | tr '-' '_')
+echo "<meta http-equiv=\"refresh\" content=\"0; url=${CRATE_NAME}/index.html\">" > target/doc/index.html"#,
+            )
+            .name("Generate Redirect Index"),
+        )
+        .add_step(Step::run(format!(
+            r#"
+            cd target/doc
+            git init
+            git config user.name "github-actions[bot]"
+            git config user.email "github-actions[bot]@users.noreply.github.com"
+            git add .
+            git commit -m "docs: Publish generated documentation"
+            git push --force "https://x-access-token:${{{{ secrets.GITHUB_TOKEN }}}}@github.com/${{{{ github.repository }}}}.git" "HEAD:{branch}"
+        "#,
+            branch = docs.branch
+        )))
+}
+
+/// Builds the coverage job that runs the test suite under `cargo-llvm-cov`
+/// and uploads the resulting `lcov.info` to Codecov.
+fn coverage_job(coverage: &Coverage) -> Job {
+    let codecov = Step::uses("codecov", "codecov-action", "v4")
+        .name("Upload to Codecov")
+        .add_with(("files", "lcov.info"))
+        .add_with(("fail_ci_if_error", "true"))
+        .add_env(Env::new("CODECOV_TOKEN", "${{ secrets.CODECOV_TOKEN }}"));
+
+    let mut job = Job::new("Coverage")
+        .permissions(Permissions::default().contents(Level::Read))
         .add_step(Step::checkout())
-        .add_step(Toolchain::default().add_stable().add_nightly().add_fmt())
+        .add_step(Toolchain::default().add_nightly())
+        .add_step(Step::run("cargo install cargo-llvm-cov --locked").name("Install cargo-llvm-cov"))
+        .add_step(
+            Cargo::new("llvm-cov")
+                .nightly()
+                .args("--workspace --all-features --lcov --output-path lcov.info")
+                .name("Collect Coverage"),
+        );
+
+    if coverage.target.is_some() || coverage.fail_on_decrease.is_some() {
+        job = job.add_step(codecov_status_gate_step(coverage));
+    }
+
+    job.add_step(codecov)
+}
+
+/// Writes a `codecov.yml` declaring the project status gate, since
+/// `codecov/codecov-action` has no `target`/`threshold` inputs of its own —
+/// those are `codecov.yml` keys read by the Codecov backend when it
+/// evaluates the uploaded report.
+fn codecov_status_gate_step(coverage: &Coverage) -> Step {
+    let target = coverage
+        .target
+        .map(|target| format!("{target}%"))
+        .unwrap_or_else(|| "auto".into());
+
+    let threshold = coverage
+        .fail_on_decrease
+        .map(|fail_on_decrease| format!("{fail_on_decrease}%"))
+        .unwrap_or_else(|| "0%".into());
+
+    Step::run(format!(
+        r#"cat > codecov.yml <<EOF
+coverage:
+  status:
+    project:
+      default:
+        target: {target}
+        threshold: {threshold}
+EOF"#
+    ))
+    .name("Generate codecov.yml")
+}
+
+/// Builds the job(s) that verify the crate on its minimum supported Rust
+/// version. When the MSRV is auto-detected, a small `detect-msrv` job reads
+/// it from `rust-toolchain`/`rust-toolchain.toml` and publishes it as a job
+/// output that the `msrv` job depends on; a pinned version skips detection.
+fn msrv_jobs(msrv: &MsrvCheck) -> Vec<(&'static str, Job)> {
+    match msrv {
+        MsrvCheck::Detect => {
+            let detect = Job::new("Detect MSRV")
+                .add_step(Step::checkout())
+                .add_step(
+                    Step::run(
+                        r#"MSRV=$(cat ./rust-toolchain 2>/dev/null || grep -oP 'channel\s*=\s*"\K[^"]+' ./rust-toolchain.toml)
+echo "msrv=$MSRV" >> "$GITHUB_OUTPUT""#,
+                    )
+                    .id("detect")
+                    .name("Detect MSRV"),
+                )
+                .add_output("msrv", "${{ steps.detect.outputs.msrv }}");
+
+            let check = Job::new("MSRV")
+                .add_needs(detect.clone())
+                .add_step(Step::checkout())
+                .add_step(Toolchain::default().add_version("${{ needs.detect-msrv.outputs.msrv }}"))
+                .add_step(
+                    Cargo::new("check")
+                        .args("--all-targets --workspace")
+                        .name("Cargo Check"),
+                );
+
+            vec![("detect-msrv", detect), ("msrv", check)]
+        }
+        MsrvCheck::Pinned(version) => {
+            let check = Job::new("MSRV")
+                .add_step(Step::checkout())
+                .add_step(Toolchain::default().add_version(version.clone()))
+                .add_step(
+                    Cargo::new("check")
+                        .args("--all-targets --workspace")
+                        .name("Cargo Check"),
+                );
+
+            vec![("msrv", check)]
+        }
+    }
+}
+
+fn lint_and_fmt_fix_job(cache: bool, fast_linker: bool) -> Job {
+    let mut job = Job::new("Auto Fix Lint and Fmt")
+        .permissions(Permissions::default().contents(Level::Write))
+        .cond(Context::github().event_name().eq("pull_request".into())) // Ensure it's a PR
+        .add_step(Step::checkout());
+
+    if fast_linker {
+        job = job.add_step(setup_mold_step());
+    }
+
+    if cache {
+        job = job.add_step(cache_step(None));
+    }
+
+    job.add_step(Toolchain::default().add_stable().add_nightly().add_fmt())
         .add_step(
             Cargo::new("fmt")
                 .nightly()
@@ -179,8 +826,14 @@ fn release_pr_job(cond: Context<bool>, build: &Job, permissions: Permissions) ->
         .add_step(Release::default().command(Command::ReleasePR))
 }
 
-fn release_job(cond: &Context<bool>, build: &Job, permissions: &Permissions) -> Job {
-    Job::new("Release")
+fn release_job(
+    cond: &Context<bool>,
+    build: &Job,
+    permissions: &Permissions,
+    cache: bool,
+    fast_linker: bool,
+) -> Job {
+    let mut job = Job::new("Release")
         .cond(cond.clone())
         .add_needs(build.clone())
         .add_env(Env::github())
@@ -189,6 +842,85 @@ fn release_job(cond: &Context<bool>, build: &Job, permissions: &Permissions) ->
             "${{ secrets.CARGO_REGISTRY_TOKEN }}",
         ))
         .permissions(permissions.clone())
-        .add_step(Step::checkout())
-        .add_step(Release::default().command(Command::Release))
+        .add_step(Step::checkout());
+
+    if fast_linker {
+        job = job.add_step(setup_mold_step());
+    }
+
+    if cache {
+        job = job.add_step(cache_step(None));
+    }
+
+    job.add_step(Release::default().command(Command::Release))
+}
+
+/// Caches `~/.cargo/registry`, `~/.cargo/git` and `./target`, keyed on
+/// `Cargo.lock` so dependency downloads and incremental build artifacts
+/// survive across runs. `extra_key` lets callers fold extra context (such as
+/// the matrix toolchain) into the cache key so cells don't clobber each
+/// other's caches.
+fn cache_step(extra_key: Option<&str>) -> Step {
+    let key = match extra_key {
+        Some(extra) => {
+            format!("${{{{ runner.os }}}}-{extra}-${{{{ hashFiles('**/Cargo.lock') }}}}")
+        }
+        None => "${{ runner.os }}-${{ hashFiles('**/Cargo.lock') }}".to_string(),
+    };
+
+    Step::uses("actions", "cache", "v4")
+        .name("Cache Cargo Dependencies")
+        .add_with(("path", "~/.cargo/registry\n~/.cargo/git\n./target"))
+        .add_with(("key", key))
+}
+
+/// Installs `mold` and wires it up as the linker via `setup-mold`, which is
+/// usually much faster than the platform default linker.
+fn setup_mold_step() -> Step {
+    Step::uses("rui314", "setup-mold", "v1").name("Setup Mold Linker")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn always_policy_benchmarks_inline_with_no_standalone_job() {
+        let workflow = Workflow::default()
+            .name("Test CI")
+            .benchmarks(true)
+            .to_github_workflow();
+
+        let build = format!("{:?}", workflow.jobs.get("build"));
+        assert!(build.contains("Cargo Bench"));
+        assert!(!workflow.jobs.contains_key("benchmarks"));
+    }
+
+    #[test]
+    fn nightly_policy_runs_benchmarks_in_their_own_job() {
+        let workflow = Workflow::default()
+            .name("Test CI")
+            .benchmarks(true)
+            .benchmark_policy(BenchmarkPolicy::Nightly("0 3 * * *".into()))
+            .to_github_workflow();
+
+        let build = format!("{:?}", workflow.jobs.get("build"));
+        assert!(!build.contains("Cargo Bench"));
+
+        let benchmarks = workflow
+            .jobs
+            .get("benchmarks")
+            .expect("standalone benchmarks job");
+        assert!(format!("{benchmarks:?}").contains("Cargo Bench"));
+    }
+
+    #[test]
+    fn dependency_updates_adds_a_scheduled_job() {
+        let workflow = Workflow::default()
+            .name("Test CI")
+            .dependency_updates(DependencyUpdates::default())
+            .to_github_workflow();
+
+        assert!(workflow.jobs.contains_key("update-dependencies"));
+    }
 }